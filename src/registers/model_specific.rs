@@ -1,5 +1,6 @@
 //! Functions to read and write model specific registers.
 
+use crate::addr::VirtAddr;
 use crate::registers::rflags::RFlags;
 use crate::structures::gdt::SegmentSelector;
 use crate::PrivilegeLevel;
@@ -18,6 +19,88 @@ impl Msr {
     }
 }
 
+/// Error returned by the fault-safe [`Msr::try_read`]/[`Msr::try_write`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MsrAccessError {
+    /// The caller-supplied availability check reported that this MSR is not
+    /// implemented on the running CPU.
+    NotAvailable,
+}
+
+/// Declares a model specific register together with its `MSR` constant and
+/// typed accessors.
+///
+/// `type = u64` generates a plain `read_raw`/`write_raw` pair. `type = SomeFlags`
+/// (a `bitflags!`-generated type) additionally generates a typed `read`/`write`
+/// that preserves reserved bits, following the same pattern `Efer` uses.
+macro_rules! msr {
+    ($(#[$docs:meta])* $name:ident = $addr:expr, type = u64) => {
+        $(#[$docs])*
+        #[derive(Debug)]
+        pub struct $name;
+
+        impl $name {
+            /// The underlying model specific register.
+            pub const MSR: Msr = Msr($addr);
+        }
+
+        #[cfg(target_arch = "x86_64")]
+        impl $name {
+            /// Read the raw value of this register.
+            pub fn read_raw() -> u64 {
+                unsafe { Self::MSR.read() }
+            }
+
+            /// Write the raw value of this register.
+            pub unsafe fn write_raw(value: u64) {
+                Self::MSR.write(value);
+            }
+        }
+    };
+    ($(#[$docs:meta])* $name:ident = $addr:expr, type = $flags:ident) => {
+        $(#[$docs])*
+        #[derive(Debug)]
+        pub struct $name;
+
+        impl $name {
+            /// The underlying model specific register.
+            pub const MSR: Msr = Msr($addr);
+        }
+
+        #[cfg(target_arch = "x86_64")]
+        impl $name {
+            /// Read the current flags.
+            pub fn read() -> $flags {
+                $flags::from_bits_truncate(Self::read_raw())
+            }
+
+            /// Read the current raw flags.
+            pub fn read_raw() -> u64 {
+                unsafe { Self::MSR.read() }
+            }
+
+            /// Write the flags, preserving the value of reserved fields.
+            ///
+            /// Unsafe because it's possible to break memory safety, e.g. by
+            /// disabling memory protections this register controls.
+            pub unsafe fn write(flags: $flags) {
+                let old_value = Self::read_raw();
+                let reserved = old_value & !($flags::all().bits());
+                let new_value = reserved | flags.bits();
+                Self::write_raw(new_value);
+            }
+
+            /// Write the raw flags, not preserving any bits.
+            ///
+            /// Unsafe because it's possible to break memory safety, e.g. by
+            /// disabling memory protections this register controls.
+            pub unsafe fn write_raw(value: u64) {
+                Self::MSR.write(value);
+            }
+        }
+    };
+}
+
 /// The Extended Feature Enable Register.
 #[derive(Debug)]
 pub struct Efer;
@@ -106,7 +189,6 @@ bitflags! {
 #[cfg(target_arch = "x86_64")]
 mod x86_64 {
     use super::*;
-    use crate::addr::VirtAddr;
 
     impl Msr {
         /// Read 64 bits msr register.
@@ -122,6 +204,42 @@ mod x86_64 {
             let high = (value >> 32) as u32;
             asm!("wrmsr" :: "{ecx}" (self.0), "{eax}" (low), "{edx}" (high) : "memory" : "volatile" );
         }
+
+        /// Reads this MSR after calling `is_available` to check whether it's
+        /// actually implemented on the running CPU.
+        ///
+        /// `read` executes `rdmsr` unconditionally, which raises a `#GP`
+        /// fault if the MSR is unimplemented on the running CPU — a real
+        /// hazard when probing a model-specific register across vendors and
+        /// steppings. `is_available` is typically a CPUID feature-bit check,
+        /// but can just as well call into a kernel's own `#GP`-recovery hook
+        /// if one is installed.
+        pub unsafe fn try_read(
+            &self,
+            is_available: impl FnOnce() -> bool,
+        ) -> Result<u64, MsrAccessError> {
+            if !is_available() {
+                return Err(MsrAccessError::NotAvailable);
+            }
+            Ok(self.read())
+        }
+
+        /// Writes this MSR after calling `is_available` to check whether it's
+        /// actually implemented on the running CPU.
+        ///
+        /// See [`Msr::try_read`] for when to prefer this over the
+        /// unconditional `write`.
+        pub unsafe fn try_write(
+            &mut self,
+            value: u64,
+            is_available: impl FnOnce() -> bool,
+        ) -> Result<(), MsrAccessError> {
+            if !is_available() {
+                return Err(MsrAccessError::NotAvailable);
+            }
+            self.write(value);
+            Ok(())
+        }
     }
 
     impl Efer {
@@ -339,3 +457,497 @@ mod x86_64 {
         }
     }
 }
+
+/// Configuration for the `SYSCALL`/`SYSRET` fast system call mechanism.
+///
+/// Bundles everything [`Star`], [`LStar`] and [`SFMask`] need so a kernel can
+/// bring up fast system calls with a single [`Syscall::init`] call instead of
+/// coordinating `Efer`, `Star`, `LStar` and `SFMask` by hand.
+///
+/// `kernel_code_selector` and `user_code_selector` assume the common GDT
+/// layout where each code segment's matching data segment sits 8 bytes below
+/// it (`kernel_data = kernel_code + 8`, `user_data = user_code - 8`), which is
+/// what `Star::write` requires of its selectors.
+#[derive(Debug)]
+pub struct SyscallConfig {
+    /// Ring 0 CS selector, used directly on `SYSCALL` entry.
+    pub kernel_code_selector: SegmentSelector,
+    /// Ring 3 CS selector, used directly on `SYSRET`.
+    pub user_code_selector: SegmentSelector,
+    /// Virtual address of the kernel's `SYSCALL` entry point, written to `LStar`.
+    pub handler: VirtAddr,
+    /// `RFlags` bits to clear on entry to the handler, written to `SFMask`.
+    pub flags_mask: RFlags,
+}
+
+/// Entry point for configuring fast system calls.
+#[derive(Debug)]
+pub struct Syscall;
+
+#[cfg(target_arch = "x86_64")]
+impl Syscall {
+    /// Performs the full `SYSCALL`/`SYSRET` bring-up: validates and writes
+    /// `Star`, points `LStar` at `config.handler`, writes `SFMask`, and
+    /// enables `EferFlags::SYSTEM_CALL_EXTENSIONS`.
+    ///
+    /// This mirrors manually calling `Star::write`, `LStar::write`,
+    /// `SFMask::write` and `Efer::update`, in that order, except that `Star`'s
+    /// selector-offset and privilege validation runs first so the other
+    /// registers are left untouched if `config` is invalid.
+    ///
+    /// # Safety
+    /// The caller must ensure `config.handler` points to a valid `SYSCALL`
+    /// entry stub and that `config.flags_mask` clears the flags that stub
+    /// expects to be cleared on entry. Like `Efer::update`, this can break
+    /// memory safety if misconfigured.
+    pub unsafe fn init(config: &SyscallConfig) -> Result<(), &'static str> {
+        let user_ss_sysret = SegmentSelector(config.user_code_selector.0 - 8);
+        let kernel_ss_syscall = SegmentSelector(config.kernel_code_selector.0 + 8);
+
+        Star::write(
+            config.user_code_selector,
+            user_ss_sysret,
+            config.kernel_code_selector,
+            kernel_ss_syscall,
+        )?;
+
+        LStar::write(config.handler);
+        SFMask::write(config.flags_mask);
+
+        Efer::update(|flags| {
+            flags.insert(EferFlags::SYSTEM_CALL_EXTENSIONS);
+        });
+
+        Ok(())
+    }
+}
+
+bitflags! {
+    /// Flags of the IA32_APIC_BASE MSR.
+    pub struct ApicBaseFlags: u64 {
+        /// Set on the bootstrap processor.
+        const BSP = 1 << 8;
+        /// Enables x2APIC mode.
+        const X2APIC_ENABLE = 1 << 10;
+        /// Globally enables the APIC, covering both xAPIC and x2APIC modes.
+        const APIC_GLOBAL_ENABLE = 1 << 11;
+    }
+}
+
+msr! {
+    /// The APIC Base Address Register.
+    ApicBase = 0x1B, type = ApicBaseFlags
+}
+
+#[cfg(target_arch = "x86_64")]
+impl ApicBase {
+    /// Reads the APIC base physical address encoded in bits 12..36 of this
+    /// register.
+    pub fn base_address() -> u64 {
+        Self::read_raw() & 0x0000_000F_FFFF_F000
+    }
+}
+
+msr! {
+    /// The Page Attribute Table register.
+    Pat = 0x277, type = u64
+}
+
+msr! {
+    /// Variable-range MTRR base for the MTRR pair at index 0.
+    ///
+    /// Higher-indexed pairs are located at `PhysBase0 + 2 * n`/`PhysMask0 + 2 * n`.
+    MtrrPhysBase0 = 0x200, type = u64
+}
+
+msr! {
+    /// Variable-range MTRR mask for the MTRR pair at index 0.
+    ///
+    /// Higher-indexed pairs are located at `PhysBase0 + 2 * n`/`PhysMask0 + 2 * n`.
+    MtrrPhysMask0 = 0x201, type = u64
+}
+
+msr! {
+    /// Fixed-range MTRR covering the 0x00000-0x7FFFF range.
+    MtrrFix64k00000 = 0x250, type = u64
+}
+
+msr! {
+    /// Fixed-range MTRR covering the 0x80000-0x9FFFF range.
+    MtrrFix16k80000 = 0x258, type = u64
+}
+
+msr! {
+    /// Fixed-range MTRR covering the 0xC0000-0xC7FFF range.
+    MtrrFix4kC0000 = 0x268, type = u64
+}
+
+msr! {
+    /// The MTRR default-type register, enabling MTRRs and setting the default
+    /// memory type for ranges not covered by a fixed or variable MTRR.
+    MtrrDefType = 0x2FF, type = u64
+}
+
+msr! {
+    /// TSC auxiliary register, typically used to store a CPU/node id read
+    /// alongside the timestamp counter via `rdtscp`.
+    TscAux = 0xC000_0103, type = u64
+}
+
+msr! {
+    /// Maximum performance frequency clock count, used together with
+    /// `IA32_APERF` to measure the actual CPU frequency.
+    Mperf = 0xE7, type = u64
+}
+
+msr! {
+    /// Actual performance frequency clock count, used together with
+    /// `IA32_MPERF` to measure the actual CPU frequency.
+    Aperf = 0xE8, type = u64
+}
+
+/// AMD memory-encryption support: Secure Memory Encryption (SME) and Secure
+/// Encrypted Virtualization (SEV/SEV-ES/SEV-SNP).
+pub mod amd_sev {
+    use super::*;
+
+    bitflags! {
+        /// Flags of the System Configuration Register.
+        pub struct SysCfgFlags: u64 {
+            /// Enables transparent memory encryption (SME).
+            const MEM_ENCRYPTION_ENABLE = 1 << 23;
+            /// Enables Secure Nested Paging (SEV-SNP).
+            const SECURE_NESTED_PAGING_ENABLE = 1 << 24;
+        }
+    }
+
+    msr! {
+        /// The System Configuration Register.
+        SysCfg = 0xC001_0010, type = SysCfgFlags
+    }
+
+    bitflags! {
+        /// Flags of the SEV_STATUS register, reporting which encrypted-memory
+        /// modes are active for the running guest.
+        pub struct SevStatusFlags: u64 {
+            /// SEV is enabled.
+            const SEV_ENABLED = 1 << 0;
+            /// SEV-ES is enabled.
+            const SEV_ES_ENABLED = 1 << 1;
+            /// SEV-SNP is enabled.
+            const SEV_SNP_ENABLED = 1 << 2;
+        }
+    }
+
+    msr! {
+        /// The SEV Status Register, reporting the encrypted-memory modes
+        /// active for the running guest.
+        SevStatus = 0xC001_0131, type = SevStatusFlags
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    /// Reads CPUID leaf `0x8000_001F` and returns the position of the C-bit
+    /// (the page-table bit that marks a physical page as encrypted) within a
+    /// physical address, as reported by the running CPU.
+    ///
+    /// Callers typically use this to build a mask, e.g.
+    /// `1u64 << c_bit_position()`, and OR or AND it into page-table entries.
+    ///
+    /// Uses `core::arch::x86_64::__cpuid` rather than colon-syntax inline
+    /// asm like this module's `rdmsr`/`wrmsr`, since `ebx` is LLVM-reserved on
+    /// x86-64 and can't be named as a direct output operand there.
+    pub fn c_bit_position() -> u8 {
+        let result = unsafe { core::arch::x86_64::__cpuid(0x8000_001F) };
+        (result.ebx & 0x3F) as u8
+    }
+}
+
+/// A snapshot of the model specific registers commonly needed to context
+/// switch a task or save/restore a vCPU's state: `Efer`, the FS/GS/KernelGS
+/// bases, the `SYSCALL` registers, `TscAux`, and `Mperf`/`Aperf`.
+///
+/// Unlike the per-register `read`/`write` methods, [`Registers::read_all`]
+/// and [`Registers::restore`] capture or apply the whole set in a single
+/// call. Fields are raw `u64`s so the struct is trivially `Copy` and can be
+/// stored in a task control block or serialized as guest state.
+#[derive(Debug, Clone, Copy)]
+pub struct Registers {
+    /// Raw value of `Efer`.
+    pub efer: u64,
+    /// Raw value of `FsBase`.
+    pub fs_base: u64,
+    /// Raw value of `GsBase`.
+    pub gs_base: u64,
+    /// Raw value of `KernelGsBase`.
+    pub kernel_gs_base: u64,
+    /// Raw value of `Star`.
+    pub star: u64,
+    /// Raw value of `LStar`.
+    pub lstar: u64,
+    /// Raw value of `SFMask`.
+    pub sfmask: u64,
+    /// Raw value of `TscAux`.
+    pub tsc_aux: u64,
+    /// Raw value of `Mperf`.
+    pub mperf: u64,
+    /// Raw value of `Aperf`.
+    pub aperf: u64,
+}
+
+#[cfg(target_arch = "x86_64")]
+impl Registers {
+    /// Checks whether `RDTSCP`, and thus `IA32_TSC_AUX`, is available, via
+    /// `CPUID.80000001H:EDX[27]`.
+    fn tsc_aux_available() -> bool {
+        unsafe { core::arch::x86_64::__cpuid(0x8000_0001).edx & (1 << 27) != 0 }
+    }
+
+    /// Checks whether `IA32_MPERF`/`IA32_APERF` are available, via
+    /// `CPUID.06H:ECX[0]`.
+    fn mperf_aperf_available() -> bool {
+        unsafe { core::arch::x86_64::__cpuid(0x6).ecx & 1 != 0 }
+    }
+
+    /// Reads all registers captured by this snapshot.
+    ///
+    /// `tsc_aux`, `mperf` and `aperf` are not architecturally guaranteed:
+    /// unlike the other fields, they are only read via [`Msr::try_read`], and
+    /// read as `0` instead of raising a `#GP` on a CPU that lacks `RDTSCP` or
+    /// hardware coordination feedback respectively.
+    pub fn read_all() -> Registers {
+        unsafe {
+            Registers {
+                efer: Efer::MSR.read(),
+                fs_base: FsBase::MSR.read(),
+                gs_base: GsBase::MSR.read(),
+                kernel_gs_base: KernelGsBase::MSR.read(),
+                star: Star::MSR.read(),
+                lstar: LStar::MSR.read(),
+                sfmask: SFMask::MSR.read(),
+                tsc_aux: TscAux::MSR.try_read(Self::tsc_aux_available).unwrap_or(0),
+                mperf: Mperf::MSR.try_read(Self::mperf_aperf_available).unwrap_or(0),
+                aperf: Aperf::MSR.try_read(Self::mperf_aperf_available).unwrap_or(0),
+            }
+        }
+    }
+
+    /// Writes all captured registers back.
+    ///
+    /// # Safety
+    /// Restoring `efer` can break memory safety, e.g. by disabling long mode
+    /// or no-execute protection. The caller must ensure the snapshot being
+    /// restored is one this code is prepared to keep running under.
+    ///
+    /// `tsc_aux`, `mperf` and `aperf` are only written back if the running
+    /// CPU reports support for them, mirroring `read_all`; otherwise they are
+    /// silently skipped rather than raising a `#GP`.
+    pub unsafe fn restore(&self) {
+        Efer::MSR.write(self.efer);
+        FsBase::MSR.write(self.fs_base);
+        GsBase::MSR.write(self.gs_base);
+        KernelGsBase::MSR.write(self.kernel_gs_base);
+        Star::MSR.write(self.star);
+        LStar::MSR.write(self.lstar);
+        SFMask::MSR.write(self.sfmask);
+        let _ = TscAux::MSR.try_write(self.tsc_aux, Self::tsc_aux_available);
+        let _ = Mperf::MSR.try_write(self.mperf, Self::mperf_aperf_available);
+        let _ = Aperf::MSR.try_write(self.aperf, Self::mperf_aperf_available);
+    }
+}
+
+/// Intel architectural performance-monitoring: the fixed-function counters,
+/// and the programmable `IA32_PERFEVTSELx`/`IA32_PMCx` counter pairs.
+pub mod perfmon {
+    use super::*;
+
+    bitflags! {
+        /// Flags of the IA32_PERF_GLOBAL_CTRL register, globally enabling or
+        /// disabling the fixed and general-purpose performance counters.
+        pub struct PerfGlobalCtrlFlags: u64 {
+            /// Enables general-purpose counter 0 (`IA32_PMC0`).
+            const PMC0_ENABLE = 1 << 0;
+            /// Enables general-purpose counter 1 (`IA32_PMC1`).
+            const PMC1_ENABLE = 1 << 1;
+            /// Enables general-purpose counter 2 (`IA32_PMC2`).
+            const PMC2_ENABLE = 1 << 2;
+            /// Enables general-purpose counter 3 (`IA32_PMC3`).
+            const PMC3_ENABLE = 1 << 3;
+            /// Enables fixed-function counter 0 (instructions retired).
+            const FIXED_CTR0_ENABLE = 1 << 32;
+            /// Enables fixed-function counter 1 (unhalted core cycles).
+            const FIXED_CTR1_ENABLE = 1 << 33;
+            /// Enables fixed-function counter 2 (unhalted reference cycles).
+            const FIXED_CTR2_ENABLE = 1 << 34;
+        }
+    }
+
+    msr! {
+        /// Enables or disables the fixed and general-purpose performance
+        /// counters globally; a counter only counts while both this and its
+        /// own per-counter control bits allow it to.
+        PerfGlobalCtrl = 0x38F, type = PerfGlobalCtrlFlags
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    impl PerfGlobalCtrl {
+        /// Enables the given counters, preserving the enabled state of the others.
+        pub unsafe fn enable(flags: PerfGlobalCtrlFlags) {
+            let current = Self::read();
+            Self::write(current | flags);
+        }
+
+        /// Disables the given counters, preserving the enabled state of the others.
+        pub unsafe fn disable(flags: PerfGlobalCtrlFlags) {
+            let current = Self::read();
+            Self::write(current & !flags);
+        }
+    }
+
+    bitflags! {
+        /// Flags of the IA32_FIXED_CTR_CTRL register, controlling the
+        /// fixed-function performance counters.
+        pub struct FixedCtrCtrlFlags: u64 {
+            /// Fixed counter 0 counts in ring 0..2.
+            const FIXED_CTR0_ENABLE_OS = 1 << 0;
+            /// Fixed counter 0 counts in ring 3.
+            const FIXED_CTR0_ENABLE_USR = 1 << 1;
+            /// Fixed counter 0 raises a performance-monitoring interrupt on overflow.
+            const FIXED_CTR0_PMI = 1 << 3;
+            /// Fixed counter 1 counts in ring 0..2.
+            const FIXED_CTR1_ENABLE_OS = 1 << 4;
+            /// Fixed counter 1 counts in ring 3.
+            const FIXED_CTR1_ENABLE_USR = 1 << 5;
+            /// Fixed counter 1 raises a performance-monitoring interrupt on overflow.
+            const FIXED_CTR1_PMI = 1 << 7;
+            /// Fixed counter 2 counts in ring 0..2.
+            const FIXED_CTR2_ENABLE_OS = 1 << 8;
+            /// Fixed counter 2 counts in ring 3.
+            const FIXED_CTR2_ENABLE_USR = 1 << 9;
+            /// Fixed counter 2 raises a performance-monitoring interrupt on overflow.
+            const FIXED_CTR2_PMI = 1 << 11;
+        }
+    }
+
+    msr! {
+        /// Controls the fixed-function performance counters.
+        FixedCtrCtrl = 0x38D, type = FixedCtrCtrlFlags
+    }
+
+    msr! {
+        /// Fixed-function counter 0: instructions retired.
+        FixedCtr0 = 0x309, type = u64
+    }
+
+    msr! {
+        /// Fixed-function counter 1: unhalted core cycles.
+        FixedCtr1 = 0x30A, type = u64
+    }
+
+    msr! {
+        /// Fixed-function counter 2: unhalted reference cycles.
+        FixedCtr2 = 0x30B, type = u64
+    }
+
+    bitflags! {
+        /// The control flags of an `IA32_PERFEVTSELx` register, excluding the
+        /// event-select and unit-mask fields built by [`PerfEvtSel`].
+        pub struct PerfEvtSelFlags: u64 {
+            /// Count events while executing at ring 3.
+            const USR = 1 << 16;
+            /// Count events while executing at ring 0..2.
+            const OS = 1 << 17;
+            /// Enables edge detection of the event.
+            const EDGE = 1 << 18;
+            /// Raises a performance-monitoring interrupt on overflow.
+            const INT = 1 << 20;
+            /// Counts events from any logical processor sharing this core.
+            const ANY_THREAD = 1 << 21;
+            /// Enables the counter; cleared, the counter does not increment.
+            const EN = 1 << 22;
+            /// Inverts the counter-mask comparison.
+            const INV = 1 << 23;
+        }
+    }
+
+    /// Builder for the value of an `IA32_PERFEVTSELx` register: the
+    /// event-select and unit-mask fields plus the USR/OS/EN/INT control bits.
+    #[derive(Debug, Clone, Copy)]
+    pub struct PerfEvtSel {
+        event_select: u8,
+        unit_mask: u8,
+        counter_mask: u8,
+        flags: PerfEvtSelFlags,
+    }
+
+    impl PerfEvtSel {
+        /// Creates a new event-select configuration for the given event and unit mask.
+        pub fn new(event_select: u8, unit_mask: u8) -> PerfEvtSel {
+            PerfEvtSel {
+                event_select,
+                unit_mask,
+                counter_mask: 0,
+                flags: PerfEvtSelFlags::empty(),
+            }
+        }
+
+        /// Sets the counter-mask (CMASK) field.
+        pub fn with_counter_mask(mut self, counter_mask: u8) -> PerfEvtSel {
+            self.counter_mask = counter_mask;
+            self
+        }
+
+        /// Adds control flags, e.g. `USR | OS | EN`.
+        pub fn with_flags(mut self, flags: PerfEvtSelFlags) -> PerfEvtSel {
+            self.flags |= flags;
+            self
+        }
+
+        /// Assembles the raw `IA32_PERFEVTSELx` value.
+        pub fn bits(self) -> u64 {
+            self.event_select as u64
+                | (self.unit_mask as u64) << 8
+                | self.flags.bits()
+                | (self.counter_mask as u64) << 24
+        }
+    }
+
+    /// A programmable general-purpose performance-monitoring counter pair:
+    /// `IA32_PERFEVTSELx` (event selection, at `0x186 + index`) and
+    /// `IA32_PMCx` (the counter itself, at `0xC1 + index`).
+    #[derive(Debug)]
+    pub struct Pmc(u8);
+
+    impl Pmc {
+        /// Addresses the general-purpose counter pair at `index`. The number
+        /// of counters actually implemented is reported by CPUID leaf `0xA`.
+        pub const fn new(index: u8) -> Pmc {
+            Pmc(index)
+        }
+
+        fn evtsel_msr(&self) -> Msr {
+            Msr::new(0x186 + self.0 as u32)
+        }
+
+        fn pmc_msr(&self) -> Msr {
+            Msr::new(0xC1 + self.0 as u32)
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    impl Pmc {
+        /// Programs this counter's event selection.
+        pub unsafe fn set_event_select(&self, sel: PerfEvtSel) {
+            self.evtsel_msr().write(sel.bits());
+        }
+
+        /// Reads the current counter value.
+        pub fn read(&self) -> u64 {
+            unsafe { self.pmc_msr().read() }
+        }
+
+        /// Writes the counter value, e.g. to pre-load it before enabling.
+        pub unsafe fn write(&self, value: u64) {
+            self.pmc_msr().write(value);
+        }
+    }
+}